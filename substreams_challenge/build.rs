@@ -0,0 +1,14 @@
+use anyhow::Result;
+use substreams_ethereum::Abigen;
+
+fn main() -> Result<()> {
+    Abigen::new("Contract", "abi/erc20.json")?
+        .generate()?
+        .write_to_file("src/abi/contract.rs")?;
+
+    Abigen::new("Erc721", "abi/erc721.json")?
+        .generate()?
+        .write_to_file("src/abi/erc721.rs")?;
+
+    Ok(())
+}
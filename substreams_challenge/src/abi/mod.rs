@@ -0,0 +1,9 @@
+#[path = "contract.rs"]
+#[allow(dead_code)]
+#[allow(clippy::all)]
+pub mod contract;
+
+#[path = "erc721.rs"]
+#[allow(dead_code)]
+#[allow(clippy::all)]
+pub mod erc721;
@@ -1,14 +1,25 @@
+// The `#[substreams::handlers::map]` macro expands `String`/param-taking modules into an
+// `extern "C"` entrypoint that reconstructs the argument from a raw pointer, which trips this
+// lint on every such module regardless of body.
+#![allow(clippy::not_unsafe_ptr_arg_deref)]
+
 mod abi;
 mod pb;
 mod rpc;
 use crate::abi::contract::events::Transfer as TransferEvent;
+use crate::abi::erc721::events::Transfer as NftTransferEvent;
 use crate::rpc::TokenMeta;
-use pb::contract::v1::{Transfer, Transfers};
-use substreams::store::{StoreAdd, StoreAddInt64, StoreNew}; // TODO! 1. (Checkpoint 3.3) import the correct store type and trait
+use pb::contract::v1::{CachedTokenMeta, NftTransfer, NftTransfers, Transfer, Transfers};
+use substreams::store::{
+    DeltaInt64, Deltas, StoreAdd, StoreAddInt64, StoreGet, StoreGetProto, StoreNew,
+    StoreSetIfNotExists, StoreSetIfNotExistsProto,
+};
 use substreams::Hex;
 use substreams_entity_change::pb::entity::EntityChanges;
 use substreams_entity_change::tables::Tables as EntityChangesTables;
+use substreams_ethereum::block_view::LogView;
 use substreams_ethereum::pb::eth::v2 as eth;
+#[allow(unused_imports)]
 use substreams_ethereum::Event;
 
 #[allow(unused_imports)]
@@ -23,29 +34,23 @@ substreams_ethereum::init!(); // Macro that initializes Substreams for Ethereum.
 // which contains a list of Transfer messages.
 #[substreams::handlers::map]
 // Defines a function named map_apes that takes an Ethereum block as input.
-fn map_apes(blk: eth::Block) -> 
+fn map_apes(blk: eth::Block, token_meta_cache: StoreGetProto<CachedTokenMeta>) ->
     // The function returns a Result type that, on success, contains a Transfers message, or an error on failure.
     Result<Transfers, substreams::errors::Error> {
     // Iterate through the block logs, filter and map them to our `Transfer` protobuf
     let transfers = blk.logs().filter_map(|log| {
-        // Check if the log matches the `TransferEvent`
-        if TransferEvent::match_log(&log.log) { // Checks if the current log matches the ERC-20
-            // Create token metadata using the log address
-            let token_meta = TokenMeta::new(&log.log.address);
-            
-            // If the token name contains "Ape", map it to a `Transfer` message
-            if token_meta.name.contains("Ape") {
-                Some(Transfer {
-                    address: Hex::encode(log.log.address.clone()), // Encode address as hex
-                    name: token_meta.name.clone(), // Copy token name
-                    symbol:token_meta.symbol.clone(), // Copy token symbol
-                })
-            } else {
-                None
-            }
-        } else {
-            None
+        // Check if the log matches the `TransferEvent` and decode its from/to/value
+        let event = TransferEvent::match_and_decode(log)?;
+
+        // Create token metadata using the log address, preferring the cached lookup
+        let token_meta = resolve_token_meta(&log.log.address, &token_meta_cache);
+
+        // If the token name contains "Ape", map it to a `Transfer` message
+        if !token_meta.name.contains("Ape") {
+            return None;
         }
+
+        Some(build_transfer(&log, &event, &token_meta))
     }).collect::<Vec<Transfer>>(); // Collect the results into a vector
 
     // Return the `Transfers` message
@@ -53,32 +58,155 @@ fn map_apes(blk: eth::Block) ->
 
 }
 
-// #[substreams::handlers::store]
-// fn store_transfer_volume(transfers: Transfers) {
-//     todo!("1. add the correct store as the second function argument");
+// Same shape as `map_apes`, but the substring(s) to match against the token name or symbol are
+// passed in as a runtime module parameter (comma-separated) instead of being hardcoded, so the
+// filter can be changed without rebuilding the WASM. An empty parameter matches every token.
+#[substreams::handlers::map]
+fn map_filtered_transfers(
+    params: String,
+    blk: eth::Block,
+    token_meta_cache: StoreGetProto<CachedTokenMeta>,
+) -> Result<Transfers, substreams::errors::Error> {
+    let patterns: Vec<String> = params
+        .split(',')
+        .map(|pattern| pattern.trim().to_lowercase())
+        .filter(|pattern| !pattern.is_empty())
+        .collect();
 
-//     todo!("2. iterate over the transfers");
+    let transfers = blk.logs().filter_map(|log| {
+        let event = TransferEvent::match_and_decode(log)?;
+        let token_meta = resolve_token_meta(&log.log.address, &token_meta_cache);
+
+        if !patterns.is_empty() {
+            let name = token_meta.name.to_lowercase();
+            let symbol = token_meta.symbol.to_lowercase();
+            let matched = patterns
+                .iter()
+                .any(|pattern| name.contains(pattern) || symbol.contains(pattern));
+
+            if !matched {
+                return None;
+            }
+        }
 
-//     todo!("3. use the `.add()` method on your store to increment the value by 1");
-// }
+        Some(build_transfer(&log, &event, &token_meta))
+    }).collect::<Vec<Transfer>>();
 
-// #[substreams::handlers::map]
-// fn graph_out() -> Result<EntityChanges, substreams::errors::Error> {
-//     // Initializing EntityChanges container
-//     let mut tables = EntityChangesTables::new();
+    Ok(Transfers { transfers })
+}
 
-//     todo!("1. scroll to top of file to add the imports");
+// Looks up a token's cached name/symbol/decimals, falling back to a live `eth_call` the first
+// time a contract is seen (or if it raced ahead of `store_token_meta` populating the cache).
+fn resolve_token_meta(address: &[u8], cache: &StoreGetProto<CachedTokenMeta>) -> TokenMeta {
+    match cache.get_last(Hex::encode(address)) {
+        Some(cached) => cached.into(),
+        None => TokenMeta::new(address),
+    }
+}
 
-//     todo!("2. pass in the store as the first function argument");
+// De-duplicates token metadata lookups *within* a block: every distinct address seen here is
+// resolved and written once per block regardless of how many of `map_apes`/
+// `map_filtered_transfers`/`map_nft_transfers` would otherwise have looked it up, so those three
+// consumers share one `eth_call` set per address instead of issuing their own. A store can't
+// take itself as an input (that's a self-reference in the module DAG, which substreams rejects),
+// so this can't also skip the `eth_call`s for an address already cached from a *prior* block;
+// `set_if_not_exists` only prevents the write from clobbering that earlier value.
+#[substreams::handlers::store]
+fn store_token_meta(blk: eth::Block, store: StoreSetIfNotExistsProto<CachedTokenMeta>) {
+    let mut seen = std::collections::HashSet::new();
+
+    for log in blk.logs() {
+        if !(TransferEvent::match_log(log.log) || NftTransferEvent::match_log(log.log)) {
+            continue;
+        }
 
-//     todo!("3. pass in the second function argument");
+        let address = log.log.address.clone();
+        if !seen.insert(address.clone()) {
+            continue;
+        }
+
+        let key = Hex::encode(&address);
+        store.set_if_not_exists(log.block_index() as u64, &key, &TokenMeta::new(&address).to_cached());
+    }
+}
+
+// Matches the ERC-721 `Transfer(address,address,uint256)` signature and emits a distinct
+// `NftTransfer` (carrying `token_id` rather than `value`). Because the ERC-20 and ERC-721
+// `Transfer` topic0 hashes collide, `NftTransferEvent::match_log` disambiguates by requiring
+// all three params to be indexed (4 topics), so this never double-counts an ERC-20 log.
+#[substreams::handlers::map]
+fn map_nft_transfers(
+    blk: eth::Block,
+    token_meta_cache: StoreGetProto<CachedTokenMeta>,
+) -> Result<NftTransfers, substreams::errors::Error> {
+    let transfers = blk.logs().filter_map(|log| {
+        let event = NftTransferEvent::match_and_decode(log)?;
+        let token_meta = resolve_token_meta(&log.log.address, &token_meta_cache);
+
+        Some(NftTransfer {
+            address: Hex::encode(&log.log.address),
+            name: token_meta.name.clone(),
+            symbol: token_meta.symbol.clone(),
+            from: Hex::encode(&event.from),
+            to: Hex::encode(&event.to),
+            token_id: event.token_id.to_string(),
+            transaction_hash: Hex::encode(&log.receipt.transaction.hash),
+            ordinal: log.block_index() as u64,
+        })
+    }).collect::<Vec<NftTransfer>>();
+
+    Ok(NftTransfers { transfers })
+}
 
-//     todo!("4. iterate over the transfers");
+// Shared by `map_apes` and `map_filtered_transfers`: turns a matched log, its decoded event and
+// the token's metadata into the `Transfer` message emitted by both modules.
+fn build_transfer(log: &LogView, event: &TransferEvent, token_meta: &TokenMeta) -> Transfer {
+    Transfer {
+        address: Hex::encode(&log.log.address), // Encode address as hex
+        name: token_meta.name.clone(), // Copy token name
+        symbol: token_meta.symbol.clone(), // Copy token symbol
+        from: Hex::encode(&event.from), // Sender address
+        to: Hex::encode(&event.to), // Recipient address
+        value: event.value.to_string(), // Raw uint256 amount, as a decimal string
+        transaction_hash: Hex::encode(&log.receipt.transaction.hash),
+        ordinal: log.block_index() as u64, // Log's position within the block
+        decimals: token_meta.decimals,
+        normalized_value: event.value.to_decimal(token_meta.decimals as u64).to_string(),
+        decimals_defaulted: token_meta.decimals_defaulted,
+    }
+}
 
-//     todo!("5. get the volume from the store");
+// Accumulates, per token address, a running count of how many transfers have been seen.
+#[substreams::handlers::store]
+fn store_transfer_volume(transfers: Transfers, store: StoreAddInt64) {
+    for transfer in transfers.transfers.iter() {
+        store.add(transfer.ordinal, &transfer.address, 1);
+    }
+}
 
-//     todo!("6. create EntityChanges");
+// Turns the accumulated per-token transfer counts into subgraph entity changes, one
+// `TokenVolume` row per token touched in this block.
+#[substreams::handlers::map]
+fn graph_out(
+    transfers: Transfers,
+    deltas: Deltas<DeltaInt64>,
+) -> Result<EntityChanges, substreams::errors::Error> {
+    // Initializing EntityChanges container
+    let mut tables = EntityChangesTables::new();
+
+    for delta in deltas.deltas.iter() {
+        let address = &delta.key;
+        let volume = delta.new_value;
+
+        if let Some(token) = transfers.transfers.iter().find(|t| &t.address == address) {
+            tables
+                .create_row("TokenVolume", address)
+                .set("name", &token.name)
+                .set("symbol", &token.symbol)
+                .set("volume", volume);
+        }
+    }
 
-//     // returning EntityChanges
-//     Ok(tables.to_entity_changes())
-// }
+    // returning EntityChanges
+    Ok(tables.to_entity_changes())
+}
@@ -0,0 +1,68 @@
+// Generated from `proto/contract.proto`. Do not edit by hand, regenerate with
+// `substreams protogen ./substreams.yaml` after changing the schema.
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Transfers {
+    #[prost(message, repeated, tag = "1")]
+    pub transfers: ::prost::alloc::vec::Vec<Transfer>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Transfer {
+    #[prost(string, tag = "1")]
+    pub address: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub symbol: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub from: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub to: ::prost::alloc::string::String,
+    #[prost(string, tag = "6")]
+    pub value: ::prost::alloc::string::String,
+    #[prost(string, tag = "7")]
+    pub transaction_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "8")]
+    pub ordinal: u64,
+    #[prost(uint32, tag = "9")]
+    pub decimals: u32,
+    #[prost(string, tag = "10")]
+    pub normalized_value: ::prost::alloc::string::String,
+    #[prost(bool, tag = "11")]
+    pub decimals_defaulted: bool,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CachedTokenMeta {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub symbol: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "3")]
+    pub decimals: u32,
+    #[prost(bool, tag = "4")]
+    pub decimals_defaulted: bool,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NftTransfers {
+    #[prost(message, repeated, tag = "1")]
+    pub transfers: ::prost::alloc::vec::Vec<NftTransfer>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NftTransfer {
+    #[prost(string, tag = "1")]
+    pub address: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub symbol: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub from: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub to: ::prost::alloc::string::String,
+    #[prost(string, tag = "6")]
+    pub token_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "7")]
+    pub transaction_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "8")]
+    pub ordinal: u64,
+}
@@ -0,0 +1,7 @@
+#[rustfmt::skip]
+#[allow(dead_code)]
+pub mod contract {
+    pub mod v1 {
+        include!("contract.v1.rs");
+    }
+}
@@ -0,0 +1,65 @@
+use crate::abi;
+use crate::pb::contract::v1::CachedTokenMeta;
+
+/// `decimals()` is used to default to when the call reverts (e.g. non-standard or
+/// misbehaving ERC-20 contracts), matching the convention used by most tooling.
+const DEFAULT_DECIMALS: u32 = 18;
+
+/// Token identity resolved via `eth_call` against the token contract itself, used to enrich
+/// raw `Transfer` logs with a human-readable name/symbol and the decimals needed to normalize
+/// amounts.
+pub struct TokenMeta {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u32,
+    /// True when `decimals()` reverted or returned unparsable data and we fell back to
+    /// `DEFAULT_DECIMALS`, so downstream consumers can flag the record as approximate.
+    pub decimals_defaulted: bool,
+}
+
+impl TokenMeta {
+    pub fn new(token_address: &[u8]) -> Self {
+        let name = abi::contract::functions::Name {}
+            .call(token_address.to_vec())
+            .unwrap_or_default();
+        let symbol = abi::contract::functions::Symbol {}
+            .call(token_address.to_vec())
+            .unwrap_or_default();
+
+        // `decimals()` is declared `uint8`, but nothing stops a non-conforming contract from
+        // returning a full 32-byte word that doesn't fit that width (or in `u64` at all), so
+        // this must use a checked conversion rather than `BigInt::to_u64`, which panics.
+        let decoded_decimals = abi::contract::functions::Decimals {}
+            .call(token_address.to_vec())
+            .and_then(|decimals| u64::try_from(&decimals).ok())
+            .and_then(|decimals| u32::try_from(decimals).ok());
+
+        let (decimals, decimals_defaulted) = match decoded_decimals {
+            Some(decimals) => (decimals, false),
+            None => (DEFAULT_DECIMALS, true),
+        };
+
+        Self { name, symbol, decimals, decimals_defaulted }
+    }
+
+    /// Builds the cached, persistable form of this metadata for `store_token_meta`.
+    pub fn to_cached(&self) -> CachedTokenMeta {
+        CachedTokenMeta {
+            name: self.name.clone(),
+            symbol: self.symbol.clone(),
+            decimals: self.decimals,
+            decimals_defaulted: self.decimals_defaulted,
+        }
+    }
+}
+
+impl From<CachedTokenMeta> for TokenMeta {
+    fn from(cached: CachedTokenMeta) -> Self {
+        Self {
+            name: cached.name,
+            symbol: cached.symbol,
+            decimals: cached.decimals,
+            decimals_defaulted: cached.decimals_defaulted,
+        }
+    }
+}